@@ -0,0 +1,42 @@
+// Buffered in-memory log of ACE parse diagnostics. `DataBlocks` used to spam phase
+// timings directly to stdout via `println!`, which library consumers could neither
+// capture nor silence. Phase timings are now emitted as `tracing` events (so a host
+// application can route them through whatever subscriber it likes) and additionally
+// retained here so tools that aren't already wired up to `tracing` - a UI, a test
+// harness - can drain recent parse diagnostics programmatically.
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+
+// Only the most recent records are kept; older ones are dropped to keep memory bounded
+// for long-running processes that parse many files.
+const MAX_RETAINED_RECORDS: usize = 256;
+
+#[derive(Debug, Clone)]
+pub struct ParseLogRecord {
+    pub phase: &'static str,
+    pub message: String,
+}
+
+#[derive(Default)]
+pub struct ParseDiagnostics {
+    records: Mutex<VecDeque<ParseLogRecord>>,
+}
+
+impl ParseDiagnostics {
+    pub fn record(&self, phase: &'static str, message: impl Into<String>) {
+        let mut records = self.records.lock().unwrap();
+        if records.len() == MAX_RETAINED_RECORDS {
+            records.pop_front();
+        }
+        records.push_back(ParseLogRecord { phase, message: message.into() });
+    }
+
+    // Drain all currently retained records, leaving the buffer empty
+    pub fn drain(&self) -> Vec<ParseLogRecord> {
+        self.records.lock().unwrap().drain(..).collect()
+    }
+}
+
+pub static PARSE_DIAGNOSTICS: Lazy<ParseDiagnostics> = Lazy::new(ParseDiagnostics::default);
@@ -0,0 +1,73 @@
+// Represents the LQR data block - contains reaction Q-values (MeV), one per reaction
+// listed in MTR and in the same order.
+use crate::ace::arrays::{NxsArray, JxsArray};
+use crate::ace::blocks::DataBlockType;
+
+// See page 16 of the ACE format spec for a description of the LQR block
+#[derive(Debug, Clone, PartialEq)]
+pub struct LQR {
+    pub q_values: Vec<f64>
+}
+
+impl LQR {
+    // Process the ASCII (text) form of the block
+    pub fn process(data: &[&str]) -> Self {
+        let q_values: Vec<f64> = data
+            .iter()
+            .map(|val| val.parse::<f64>().unwrap())
+            .collect();
+
+        Self { q_values }
+    }
+
+    pub fn pull_from_ascii_xxs_array<'a>(nxs_array: &NxsArray, jxs_array: &JxsArray, xxs_array: &'a [&'a str]) -> &'a [&'a str] {
+        let (block_start, block_end) = LQR::block_bounds(nxs_array, jxs_array, xxs_array.len());
+        &xxs_array[block_start..block_end]
+    }
+
+    // Process the binary form of the block, where the XXS array has already been read
+    // as packed `f64` records
+    pub fn process_binary(data: &[f64]) -> Self {
+        Self { q_values: data.to_vec() }
+    }
+
+    pub fn pull_from_binary_xxs_array<'a>(nxs_array: &NxsArray, jxs_array: &JxsArray, xxs_array: &'a [f64]) -> &'a [f64] {
+        let (block_start, block_end) = LQR::block_bounds(nxs_array, jxs_array, xxs_array.len());
+        &xxs_array[block_start..block_end]
+    }
+
+    // Block start/end index, shared by the ASCII and binary XXS layouts. Both are
+    // 1-indexed (see LSIG::block_bounds for why), and LQR holds one Q-value per reaction,
+    // the same layout as MTR.
+    fn block_bounds(nxs_array: &NxsArray, jxs_array: &JxsArray, xxs_array_len: usize) -> (usize, usize) {
+        let block_start = jxs_array.get(&DataBlockType::LQR) - 1;
+        let num_reactions = nxs_array.ntr;
+        let mut block_end = block_start + num_reactions;
+        // Avoid issues if this is the last block in the file
+        if block_end == xxs_array_len + 1 {
+            block_end -= 1;
+        }
+        (block_start, block_end)
+    }
+}
+
+impl std::fmt::Display for LQR {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "LQR({} q-values)", self.q_values.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ace::utils::get_parsed_test_file;
+
+    #[tokio::test]
+    async fn test_lqr_parsing() {
+        let parsed_ace = get_parsed_test_file().await;
+
+        // Check contents - one Q-value per reaction, same layout as MTR
+        let lqr = parsed_ace.data_blocks.LQR.unwrap();
+        let mtr = parsed_ace.data_blocks.MTR.unwrap();
+        assert_eq!(lqr.q_values.len(), mtr.mt_values.len());
+    }
+}
@@ -9,27 +9,50 @@ pub struct LSIG {
 }
 
 impl LSIG {
-    pub fn process(data: &[f64]) -> Self {
+    // Process the ASCII (text) form of the block
+    pub fn process(data: &[&str]) -> Self {
         let xs_locs: Vec<usize> = data
             .iter()
-            .map(|val| val.to_bits() as usize)
+            .map(|val| val.parse::<f64>().unwrap() as usize)
             .collect();
 
         Self { xs_locs }
     }
 
-    pub fn pull_from_xxs_array<'a>(nxs_array: &NxsArray, jxs_array: &JxsArray, xxs_array: &'a [f64]) -> &'a [f64] {
-        // Block start index (binary XXS is zero indexed for speed)
+    pub fn pull_from_ascii_xxs_array<'a>(nxs_array: &NxsArray, jxs_array: &JxsArray, xxs_array: &'a [&'a str]) -> &'a [&'a str] {
+        let (block_start, block_end) = LSIG::block_bounds(nxs_array, jxs_array, xxs_array.len());
+        &xxs_array[block_start..block_end]
+    }
+
+    // Process the binary form of the block, where the XXS array has already been read
+    // as packed `f64` records
+    pub fn process_binary(data: &[f64]) -> Self {
+        let xs_locs: Vec<usize> = data
+            .iter()
+            .map(|val| *val as usize)
+            .collect();
+
+        Self { xs_locs }
+    }
+
+    pub fn pull_from_binary_xxs_array<'a>(nxs_array: &NxsArray, jxs_array: &JxsArray, xxs_array: &'a [f64]) -> &'a [f64] {
+        let (block_start, block_end) = LSIG::block_bounds(nxs_array, jxs_array, xxs_array.len());
+        &xxs_array[block_start..block_end]
+    }
+
+    // Block start/end index, shared by the ASCII and binary XXS layouts. Both are
+    // 1-indexed: ASCII via its "INDEX PLACEHOLDER" entry, binary via the `0.0` placeholder
+    // `read_binary_xxs_array` pushes, so `jxs_array.get(...) - 1` is valid for either.
+    fn block_bounds(nxs_array: &NxsArray, jxs_array: &JxsArray, xxs_array_len: usize) -> (usize, usize) {
         let block_start = jxs_array.get(&DataBlockType::LSIG) - 1;
         // Calculate the block end index, see the LSIG description in the ACE spec
         let num_reactions = nxs_array.ntr;
         let mut block_end = block_start + num_reactions;
         // Avoid issues if this is the last block in the file
-        if block_end == xxs_array.len() + 1 {
+        if block_end == xxs_array_len + 1 {
             block_end -= 1;
         }
-        // Return the block
-        &xxs_array[block_start..block_end]
+        (block_start, block_end)
     }
 }
 
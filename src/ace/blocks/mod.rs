@@ -1,13 +1,15 @@
 mod block_types;
 mod block_processor;
+mod diagnostics;
 mod esz;
 mod mtr;
 mod lsig;
 mod sig;
 mod lqr;
 
-pub use block_types::DataBlockType;
+pub use block_types::{DataBlockType, DataBlock};
 pub use block_processor::DataBlocks;
+pub use diagnostics::{ParseLogRecord, PARSE_DIAGNOSTICS};
 
 pub use esz::ESZ;
 pub use mtr::MTR;